@@ -6,7 +6,8 @@ use crate::{
         EncodedPoint, FromEncodedPoint, ToEncodedPoint, UncompressedPointSize, UntaggedPointSize,
     },
     weierstrass::{point, Curve},
-    AffinePoint, Error, FieldBytes, ProjectiveArithmetic, ProjectivePoint, Scalar,
+    AffinePoint, Error, FieldBytes, NonZeroScalar, ProjectiveArithmetic, ProjectivePoint, Scalar,
+    SecretKey,
 };
 use core::{
     convert::{TryFrom, TryInto},
@@ -15,6 +16,12 @@ use core::{
 };
 use ff::PrimeField;
 use generic_array::ArrayLength;
+use group::Group;
+use subtle::{Choice, CtOption};
+use zeroize::Zeroize;
+
+#[cfg(feature = "jwk")]
+use alloc::string::ToString;
 
 /// Elliptic curve public keys.
 ///
@@ -27,7 +34,7 @@ where
     FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
     Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
     AffinePoint<C>: Clone + Debug + Default + FromEncodedPoint<C> + ToEncodedPoint<C>,
-    ProjectivePoint<C>: From<AffinePoint<C>>,
+    ProjectivePoint<C>: From<AffinePoint<C>> + group::Group,
     UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
     UncompressedPointSize<C>: ArrayLength<u8>,
 {
@@ -40,7 +47,7 @@ where
     FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
     Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
     AffinePoint<C>: Clone + Debug + Default + FromEncodedPoint<C> + ToEncodedPoint<C>,
-    ProjectivePoint<C>: From<AffinePoint<C>>,
+    ProjectivePoint<C>: From<AffinePoint<C>> + group::Group,
     UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
     UncompressedPointSize<C>: ArrayLength<u8>,
 {
@@ -51,9 +58,15 @@ where
             .and_then(TryInto::try_into)
     }
 
-    /// Convert an [`AffinePoint`] into a [`PublicKey`]
-    pub fn from_affine(point: AffinePoint<C>) -> Self {
-        Self { point }
+    /// Convert an [`AffinePoint`] into a [`PublicKey`].
+    ///
+    /// Returns an [`Error`] if the given point is the identity (i.e. point at
+    /// infinity), which is not a valid public key.
+    pub fn from_affine(point: AffinePoint<C>) -> Result<Self, Error> {
+        let is_identity = ProjectivePoint::<C>::from(point.clone()).is_identity();
+        CtOption::new(Self { point }, !is_identity)
+            .into_option()
+            .ok_or(Error)
     }
 
     /// Convert this [`PublicKey`] to a [`ProjectivePoint`] for the given curve
@@ -68,7 +81,7 @@ where
     FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
     Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
     AffinePoint<C>: Clone + Debug + Default + FromEncodedPoint<C> + ToEncodedPoint<C>,
-    ProjectivePoint<C>: From<AffinePoint<C>>,
+    ProjectivePoint<C>: From<AffinePoint<C>> + group::Group,
     UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
     UncompressedPointSize<C>: ArrayLength<u8>,
 {
@@ -83,7 +96,7 @@ where
     FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
     Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
     AffinePoint<C>: Clone + Debug + Default + FromEncodedPoint<C> + ToEncodedPoint<C>,
-    ProjectivePoint<C>: From<AffinePoint<C>>,
+    ProjectivePoint<C>: From<AffinePoint<C>> + group::Group,
     UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
     UncompressedPointSize<C>: ArrayLength<u8>,
 {
@@ -100,7 +113,7 @@ where
     FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
     Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
     AffinePoint<C>: Clone + Debug + Default + FromEncodedPoint<C> + ToEncodedPoint<C>,
-    ProjectivePoint<C>: From<AffinePoint<C>>,
+    ProjectivePoint<C>: From<AffinePoint<C>> + group::Group,
     UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
     UncompressedPointSize<C>: ArrayLength<u8>,
 {
@@ -117,7 +130,7 @@ where
     FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
     Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
     AffinePoint<C>: Clone + Debug + Default + FromEncodedPoint<C> + ToEncodedPoint<C>,
-    ProjectivePoint<C>: From<AffinePoint<C>>,
+    ProjectivePoint<C>: From<AffinePoint<C>> + group::Group,
     UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
     UncompressedPointSize<C>: ArrayLength<u8>,
 {
@@ -134,7 +147,7 @@ where
     FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
     Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
     AffinePoint<C>: Clone + Debug + Default + FromEncodedPoint<C> + ToEncodedPoint<C>,
-    ProjectivePoint<C>: From<AffinePoint<C>>,
+    ProjectivePoint<C>: From<AffinePoint<C>> + group::Group,
     UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
     UncompressedPointSize<C>: ArrayLength<u8>,
 {
@@ -149,7 +162,7 @@ where
     FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
     Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
     AffinePoint<C>: Clone + Debug + Default + FromEncodedPoint<C> + ToEncodedPoint<C>,
-    ProjectivePoint<C>: From<AffinePoint<C>>,
+    ProjectivePoint<C>: From<AffinePoint<C>> + group::Group,
     UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
     UncompressedPointSize<C>: ArrayLength<u8>,
 {
@@ -164,13 +177,17 @@ where
     FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
     Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
     AffinePoint<C>: Clone + Debug + Default + FromEncodedPoint<C> + ToEncodedPoint<C>,
-    ProjectivePoint<C>: From<AffinePoint<C>>,
+    ProjectivePoint<C>: From<AffinePoint<C>> + group::Group,
     UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
     UncompressedPointSize<C>: ArrayLength<u8>,
 {
     /// Initialize [`PublicKey`] from an [`EncodedPoint`]
+    ///
+    /// Returns `None` if the given [`EncodedPoint`] is invalid or decodes to
+    /// the identity point.
     fn from_encoded_point(encoded_point: &EncodedPoint<C>) -> Option<Self> {
-        AffinePoint::<C>::from_encoded_point(encoded_point).map(|point| Self { point })
+        AffinePoint::<C>::from_encoded_point(encoded_point)
+            .and_then(|point| Self::from_affine(point).ok())
     }
 }
 
@@ -180,7 +197,7 @@ where
     FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
     Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
     AffinePoint<C>: Clone + Debug + Default + FromEncodedPoint<C> + ToEncodedPoint<C>,
-    ProjectivePoint<C>: From<AffinePoint<C>>,
+    ProjectivePoint<C>: From<AffinePoint<C>> + group::Group,
     UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
     UncompressedPointSize<C>: ArrayLength<u8>,
 {
@@ -190,3 +207,460 @@ where
         self.point.to_encoded_point(compress)
     }
 }
+
+/// Decompress an elliptic curve point from an x-coordinate and a sign bit
+/// indicating the parity of `y`, recovering `y` via the curve equation
+/// `y² = x³ + ax + b` using a constant-time square root.
+pub trait DecompressPoint<C: Curve>: Sized {
+    /// Attempt to decompress an elliptic curve point from the given
+    /// x-coordinate and the parity of `y`. Returns [`CtOption`] which is
+    /// `None` if `x` is not the x-coordinate of a point on the curve.
+    fn decompress(x: &FieldBytes<C>, y_is_odd: Choice) -> CtOption<Self>;
+}
+
+/// Encode an elliptic curve point as a SEC1 "compact" x-only [`EncodedPoint`],
+/// which is possible precisely when this point's y-coordinate is the smaller
+/// (in canonical big-endian integer representation) of itself and its
+/// negation.
+pub trait ToCompactEncodedPoint<C: Curve + point::Compression> {
+    /// Serialize this value as a SEC1 compact [`EncodedPoint`]. Returns
+    /// [`CtOption`] which is `None` if this point is not compactly encodable.
+    fn to_compact_encoded_point(&self) -> CtOption<EncodedPoint<C>>;
+}
+
+/// Decode an elliptic curve point from a SEC1 "compact" x-only encoding,
+/// recovering whichever of `y`/`-y` is the smaller.
+pub trait DecompactPoint<C: Curve + point::Compression>: Sized {
+    /// Decompact an elliptic curve point from the given x-coordinate.
+    fn decompact(x: &FieldBytes<C>) -> CtOption<Self>;
+}
+
+impl<C> PublicKey<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+    AffinePoint<C>: Clone + Debug + Default + DecompressPoint<C> + FromEncodedPoint<C> + ToEncodedPoint<C>,
+    ProjectivePoint<C>: From<AffinePoint<C>> + group::Group,
+    UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+{
+    /// Decompress a [`PublicKey`] from an x-coordinate and a sign bit
+    /// indicating the parity of `y`, recovering `y` from the curve equation.
+    ///
+    /// Rejects the identity point, as with [`PublicKey::from_affine`].
+    pub fn from_x_and_sign(x: &FieldBytes<C>, y_is_odd: Choice) -> Result<Self, Error> {
+        Option::from(AffinePoint::<C>::decompress(x, y_is_odd))
+            .ok_or(Error)
+            .and_then(Self::from_affine)
+    }
+}
+
+impl<C> PublicKey<C>
+where
+    C: Curve + ProjectiveArithmetic + point::Compression,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+    AffinePoint<C>: Clone + Debug + Default + FromEncodedPoint<C> + ToEncodedPoint<C> + ToCompactEncodedPoint<C>,
+    ProjectivePoint<C>: From<AffinePoint<C>> + group::Group,
+    UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+{
+    /// Serialize this [`PublicKey`] as a SEC1 compact-encoded point, if the
+    /// underlying point is compactly encodable (see [`ToCompactEncodedPoint`]).
+    pub fn to_compact_encoded_point(&self) -> CtOption<EncodedPoint<C>> {
+        self.point.to_compact_encoded_point()
+    }
+}
+
+impl<C> PublicKey<C>
+where
+    C: Curve + ProjectiveArithmetic + point::Compression,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+    AffinePoint<C>: Clone + Debug + Default + DecompactPoint<C> + FromEncodedPoint<C> + ToEncodedPoint<C>,
+    ProjectivePoint<C>: From<AffinePoint<C>> + group::Group,
+    UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+{
+    /// Parse a [`PublicKey`] from a SEC1 compact x-only encoded point, e.g.
+    /// the 32-byte x-only form used by P-256.
+    pub fn from_compact_bytes(x: &FieldBytes<C>) -> Result<Self, Error> {
+        Option::from(AffinePoint::<C>::decompact(x))
+            .ok_or(Error)
+            .and_then(Self::from_affine)
+    }
+}
+
+impl<C> PublicKey<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+    AffinePoint<C>: Clone + Debug + Default + FromEncodedPoint<C> + ToEncodedPoint<C>,
+    ProjectivePoint<C>: From<AffinePoint<C>> + Group<Scalar = Scalar<C>>,
+    UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+{
+    /// Compute a [`PublicKey`] from a [`SecretKey`] by multiplying the
+    /// curve's generator point by the secret scalar.
+    pub fn from_secret_key(secret_key: &SecretKey<C>) -> Self {
+        let public_point = ProjectivePoint::<C>::generator() * *secret_key.to_nonzero_scalar();
+
+        Self::from_affine(public_point.into())
+            .expect("generator multiplied by a nonzero scalar is never the identity point")
+    }
+}
+
+/// Shared secret value computed via Elliptic Curve Diffie-Hellman (ECDH)
+/// key agreement, as the affine x-coordinate of `[d]·P`.
+///
+/// Zeroized on drop.
+pub struct SharedSecret<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>> + Zeroize,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+{
+    secret_bytes: FieldBytes<C>,
+}
+
+impl<C> SharedSecret<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>> + Zeroize,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+{
+    /// Shared secret bytes: the big-endian encoded x-coordinate of the
+    /// ECDH shared point.
+    pub fn as_bytes(&self) -> &FieldBytes<C> {
+        &self.secret_bytes
+    }
+}
+
+impl<C> Drop for SharedSecret<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>> + Zeroize,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+{
+    fn drop(&mut self) {
+        self.secret_bytes.zeroize();
+    }
+}
+
+/// Elliptic Curve Diffie-Hellman (ECDH) key agreement.
+pub trait DiffieHellman<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>> + Zeroize,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+    AffinePoint<C>: Clone + Debug + Default + FromEncodedPoint<C> + ToEncodedPoint<C>,
+    ProjectivePoint<C>: From<AffinePoint<C>> + Group<Scalar = Scalar<C>>,
+    UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+{
+    /// Compute the ECDH [`SharedSecret`] with the given peer [`PublicKey`].
+    fn diffie_hellman(&self, public_key: &PublicKey<C>) -> SharedSecret<C>;
+}
+
+impl<C> DiffieHellman<C> for NonZeroScalar<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>> + Zeroize,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+    AffinePoint<C>: Clone + Debug + Default + FromEncodedPoint<C> + ToEncodedPoint<C>,
+    ProjectivePoint<C>: From<AffinePoint<C>> + Group<Scalar = Scalar<C>>,
+    UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+{
+    fn diffie_hellman(&self, public_key: &PublicKey<C>) -> SharedSecret<C> {
+        let shared_point = public_key.to_projective() * **self;
+        let affine_point = AffinePoint::<C>::from(shared_point);
+        let encoded_point = affine_point.to_encoded_point(false);
+
+        let secret_bytes = encoded_point
+            .x()
+            .cloned()
+            .expect("uncompressed point always has an x-coordinate");
+
+        SharedSecret { secret_bytes }
+    }
+}
+
+/// The `id-ecPublicKey` OID used to identify elliptic curve public keys in
+/// an X.509 [`AlgorithmIdentifier`][`pkcs8::AlgorithmIdentifier`].
+#[cfg(feature = "pkcs8")]
+pub const ALGORITHM_OID: pkcs8::ObjectIdentifier = pkcs8::ObjectIdentifier::new("1.2.840.10045.2.1");
+
+/// Associates a curve OID with a curve type, allowing the curve parameter of
+/// an [`AlgorithmIdentifier`][`pkcs8::AlgorithmIdentifier`] to be filled in
+/// (or checked) when encoding (or decoding) a SPKI document.
+#[cfg(feature = "pkcs8")]
+pub trait AlgorithmParameters: Curve {
+    /// OID identifying this curve, e.g. `1.2.840.10045.3.1.7` for P-256.
+    const OID: pkcs8::ObjectIdentifier;
+}
+
+#[cfg(feature = "pkcs8")]
+impl<C> pkcs8::FromPublicKey for PublicKey<C>
+where
+    C: Curve + ProjectiveArithmetic + AlgorithmParameters,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+    AffinePoint<C>: Clone + Debug + Default + FromEncodedPoint<C> + ToEncodedPoint<C>,
+    ProjectivePoint<C>: From<AffinePoint<C>> + group::Group,
+    UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+{
+    fn from_spki(spki: pkcs8::SubjectPublicKeyInfo<'_>) -> pkcs8::Result<Self> {
+        spki.algorithm.assert_algorithm_oid(ALGORITHM_OID)?;
+
+        let parameters_oid = spki
+            .algorithm
+            .parameters_oid()
+            .map_err(|_| pkcs8::Error::ParametersMalformed)?;
+
+        if parameters_oid != C::OID {
+            return Err(pkcs8::Error::ParametersMalformed);
+        }
+
+        Self::new(spki.subject_public_key).map_err(|_| pkcs8::Error::KeyMalformed)
+    }
+}
+
+#[cfg(feature = "pkcs8")]
+impl<C> pkcs8::ToPublicKey for PublicKey<C>
+where
+    C: Curve + ProjectiveArithmetic + AlgorithmParameters + point::Compression,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+    AffinePoint<C>: Clone + Debug + Default + FromEncodedPoint<C> + ToEncodedPoint<C>,
+    ProjectivePoint<C>: From<AffinePoint<C>> + group::Group,
+    UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+{
+    fn to_public_key_der(&self) -> pkcs8::PublicKeyDocument {
+        let algorithm = pkcs8::AlgorithmIdentifier {
+            oid: ALGORITHM_OID,
+            parameters: Some(C::OID.into()),
+        };
+
+        let encoded_point = self.to_encoded_point(false);
+
+        pkcs8::SubjectPublicKeyInfo {
+            algorithm,
+            subject_public_key: encoded_point.as_bytes(),
+        }
+        .to_der()
+    }
+}
+
+/// Parse a [`PublicKey`] from PEM-encoded SPKI, e.g. text beginning with
+/// `-----BEGIN PUBLIC KEY-----`.
+#[cfg(feature = "pem")]
+impl<C> core::str::FromStr for PublicKey<C>
+where
+    C: Curve + ProjectiveArithmetic + AlgorithmParameters,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+    AffinePoint<C>: Clone + Debug + Default + FromEncodedPoint<C> + ToEncodedPoint<C>,
+    ProjectivePoint<C>: From<AffinePoint<C>> + group::Group,
+    UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+{
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        use pkcs8::FromPublicKey;
+        Self::from_public_key_pem(s).map_err(|_| Error)
+    }
+}
+
+/// Elliptic curve parameters used when encoding/decoding a [`PublicKey`] as
+/// a JSON Web Key (JWK), as defined in RFC 7518 §6.2.
+#[cfg(feature = "jwk")]
+pub trait JwkParameters: Curve {
+    /// The `crv` parameter which identifies this curve, e.g. `"P-256"`.
+    const CRV: &'static str;
+}
+
+/// A JSON Web Key (JWK) representing an elliptic curve public key, as
+/// defined in RFC 7518 §6.2: `{"kty":"EC","crv":"<name>","x":"<b64url>","y":"<b64url>"}`.
+#[cfg(feature = "jwk")]
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Jwk {
+    kty: alloc::string::String,
+    crv: alloc::string::String,
+    x: alloc::string::String,
+    y: alloc::string::String,
+}
+
+#[cfg(feature = "jwk")]
+impl<C> PublicKey<C>
+where
+    C: Curve + ProjectiveArithmetic + JwkParameters,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+    AffinePoint<C>: Clone + Debug + Default + FromEncodedPoint<C> + ToEncodedPoint<C>,
+    ProjectivePoint<C>: From<AffinePoint<C>> + group::Group,
+    UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+{
+    /// Parse a [`PublicKey`] from a JSON Web Key (JWK).
+    pub fn from_jwk(jwk: &Jwk) -> Result<Self, Error> {
+        jwk.try_into()
+    }
+
+    /// Serialize this [`PublicKey`] as a JSON Web Key (JWK).
+    pub fn to_jwk(&self) -> Jwk {
+        self.into()
+    }
+}
+
+#[cfg(feature = "jwk")]
+impl<C> TryFrom<&Jwk> for PublicKey<C>
+where
+    C: Curve + ProjectiveArithmetic + JwkParameters,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+    AffinePoint<C>: Clone + Debug + Default + FromEncodedPoint<C> + ToEncodedPoint<C>,
+    ProjectivePoint<C>: From<AffinePoint<C>> + group::Group,
+    UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+{
+    type Error = Error;
+
+    fn try_from(jwk: &Jwk) -> Result<Self, Error> {
+        if jwk.kty != "EC" || jwk.crv != C::CRV {
+            return Err(Error);
+        }
+
+        let mut x = FieldBytes::<C>::default();
+        let mut y = FieldBytes::<C>::default();
+        base64ct::Base64UrlUnpadded::decode(&jwk.x, &mut x).map_err(|_| Error)?;
+        base64ct::Base64UrlUnpadded::decode(&jwk.y, &mut y).map_err(|_| Error)?;
+
+        let mut bytes = alloc::vec::Vec::with_capacity(1 + x.len() + y.len());
+        bytes.push(0x04);
+        bytes.extend_from_slice(&x);
+        bytes.extend_from_slice(&y);
+
+        EncodedPoint::<C>::from_bytes(bytes)
+            .map_err(|_| Error)
+            .and_then(TryInto::try_into)
+    }
+}
+
+#[cfg(feature = "jwk")]
+impl<C> From<&PublicKey<C>> for Jwk
+where
+    C: Curve + ProjectiveArithmetic + JwkParameters,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+    AffinePoint<C>: Clone + Debug + Default + FromEncodedPoint<C> + ToEncodedPoint<C>,
+    ProjectivePoint<C>: From<AffinePoint<C>> + group::Group,
+    UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+{
+    fn from(public_key: &PublicKey<C>) -> Jwk {
+        let encoded_point = public_key.to_encoded_point(false);
+        let (x, y) = encoded_point
+            .x()
+            .zip(encoded_point.y())
+            .expect("uncompressed point always has x and y coordinates");
+
+        Jwk {
+            kty: "EC".to_string(),
+            crv: C::CRV.to_string(),
+            x: base64ct::Base64UrlUnpadded::encode_string(x),
+            y: base64ct::Base64UrlUnpadded::encode_string(y),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<C> serde::Serialize for PublicKey<C>
+where
+    C: Curve + ProjectiveArithmetic + point::Compression,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+    AffinePoint<C>: Clone + Debug + Default + FromEncodedPoint<C> + ToEncodedPoint<C>,
+    ProjectivePoint<C>: From<AffinePoint<C>> + group::Group,
+    UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let encoded_point = self.to_encoded_point(true);
+
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&hex::encode(encoded_point.as_bytes()))
+        } else {
+            serializer.serialize_bytes(encoded_point.as_bytes())
+        }
+    }
+}
+
+/// `serde::de::Visitor` which decodes a [`PublicKey`] from either a hex
+/// string (human-readable formats) or raw bytes (binary formats).
+#[cfg(feature = "serde")]
+struct PublicKeyVisitor<C>(core::marker::PhantomData<C>);
+
+#[cfg(feature = "serde")]
+impl<'de, C> serde::de::Visitor<'de> for PublicKeyVisitor<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+    AffinePoint<C>: Clone + Debug + Default + FromEncodedPoint<C> + ToEncodedPoint<C>,
+    ProjectivePoint<C>: From<AffinePoint<C>> + group::Group,
+    UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+{
+    type Value = PublicKey<C>;
+
+    fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("a SEC1 encoded elliptic curve point as hex or bytes")
+    }
+
+    fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        let bytes = hex::decode(s).map_err(E::custom)?;
+        PublicKey::new(&bytes).map_err(E::custom)
+    }
+
+    fn visit_bytes<E>(self, bytes: &[u8]) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        PublicKey::new(bytes).map_err(E::custom)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, C> serde::Deserialize<'de> for PublicKey<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+    AffinePoint<C>: Clone + Debug + Default + FromEncodedPoint<C> + ToEncodedPoint<C>,
+    ProjectivePoint<C>: From<AffinePoint<C>> + group::Group,
+    UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(PublicKeyVisitor(core::marker::PhantomData))
+        } else {
+            deserializer.deserialize_bytes(PublicKeyVisitor(core::marker::PhantomData))
+        }
+    }
+}